@@ -1,51 +1,44 @@
 #![doc = include_str!("../README.md")]
+#![feature(dropck_eyepatch)]
 #![allow(clippy::mut_from_ref, unstable_name_collisions)]
 
 use std::cell::{Cell, RefCell};
 use std::mem::MaybeUninit;
 use std::ptr::NonNull;
+use std::sync::Mutex;
 
+use smallvec::SmallVec;
 use sptr::Strict;
 
 const PAGE: usize = 4096;
 const HUGE_PAGE: usize = 2 * 1024 * 1024;
 
-pub struct DroplessArena<T> {
-    start: Cell<*mut T>,
-    end: Cell<*mut T>,
-    chunks: RefCell<Vec<Chunk<T>>>,
+/// A type-erased arena: unlike [`TypedArena`], one `DroplessArena` can allocate
+/// values of many different `Copy`/`Clone` types, since its chunks are raw,
+/// arbitrarily-aligned bytes rather than a `Vec<T>`.
+pub struct DroplessArena {
+    start: Cell<*mut u8>,
+    end: Cell<*mut u8>,
+    chunks: RefCell<Vec<Chunk<u8>>>,
 }
 
-impl<T> Default for DroplessArena<T> {
-    fn default() -> DroplessArena<T> {
+impl Default for DroplessArena {
+    fn default() -> DroplessArena {
         DroplessArena::new()
     }
 }
 
-impl<T> DroplessArena<T> {
-    unsafe fn alloc_raw_slice(&self, len: usize) -> *mut T {
-        self.ensure_capacity(len);
-
-        let dst = self.start.get();
-        self.start.set(dst.add(len));
-
-        dst
-    }
-}
-
-impl<T> DroplessArena<T> {
-    /// Creates a new, empty arena that can be used to allocate objects of type `T`.
+impl DroplessArena {
+    /// Creates a new, empty arena.
     ///
     /// # Example
     ///
     /// ```
     /// use pochita::DroplessArena;
     ///
-    /// let arena: DroplessArena<i32> = DroplessArena::new();
+    /// let arena = DroplessArena::new();
     /// ```
-    pub fn new() -> DroplessArena<T> {
-        assert!(std::mem::size_of::<T>() != 0);
-
+    pub fn new() -> DroplessArena {
         DroplessArena {
             start: Cell::new(std::ptr::null_mut()),
             end: Cell::new(std::ptr::null_mut()),
@@ -53,27 +46,24 @@ impl<T> DroplessArena<T> {
         }
     }
 
-    /// Determines whether the arena has enough free space to allocate an object of
-    /// type `T` with the specified additional size, in bytes.
+    /// Determines whether the arena has at least `additional` free bytes.
     ///
     /// # Example
     ///
     /// ```
     /// use pochita::DroplessArena;
     ///
-    /// let arena = DroplessArena::<i32>::new();
+    /// let arena = DroplessArena::new();
     /// assert_eq!(arena.can_allocate(10), false);
     /// arena.ensure_capacity(10);
     /// assert_eq!(arena.can_allocate(10), true);
     /// ```
     pub fn can_allocate(&self, additional: usize) -> bool {
         let available_bytes = self.end.get().addr() - self.start.get().addr();
-        let additional_bytes = additional.checked_mul(std::mem::size_of::<T>()).unwrap();
-        available_bytes >= additional_bytes
+        available_bytes >= additional
     }
 
-    /// Ensures that the arena has enough free space to allocate an object of type
-    /// `T` with the specified additional size, in bytes.
+    /// Ensures that the arena has at least `additional` free bytes.
     ///
     /// If the arena does not have enough free space, this method will reserve
     /// additional space in the arena to meet the allocation requirements.
@@ -83,7 +73,7 @@ impl<T> DroplessArena<T> {
     /// ```
     /// use pochita::DroplessArena;
     ///
-    /// let arena = DroplessArena::<i32>::new();
+    /// let arena = DroplessArena::new();
     /// assert_eq!(arena.can_allocate(10), false);
     /// arena.ensure_capacity(10);
     /// assert_eq!(arena.can_allocate(10), true);
@@ -95,8 +85,34 @@ impl<T> DroplessArena<T> {
         }
     }
 
-    /// Allocates a new object of type `T` in the arena and initializes it with the
-    /// value of the `src` argument.
+    /// Bump-allocates `layout`'s worth of space, rounding the current position
+    /// up to `layout.align()` first and reserving a new chunk if the current
+    /// one doesn't have enough room left (however it was consumed — the chunk
+    /// may be shared across many different `U`s).
+    fn alloc_raw(&self, layout: std::alloc::Layout) -> *mut u8 {
+        assert!(layout.size() != 0);
+
+        loop {
+            let start = self.start.get().addr();
+            let end = self.end.get().addr();
+
+            let align_start = start.checked_add(layout.align() - 1).unwrap() & !(layout.align() - 1);
+            match align_start.checked_add(layout.size()) {
+                Some(new_start) if new_start <= end => {
+                    let dst = self.start.get().with_addr(align_start);
+                    self.start.set(self.start.get().with_addr(new_start));
+                    return dst;
+                }
+                // Pad for alignment: the chunk's own backing storage is only
+                // byte-aligned, so its start may not satisfy `layout.align()`,
+                // and a freshly reserved chunk must have slack to round up into.
+                _ => self.reserve(layout.size() + layout.align() - 1),
+            }
+        }
+    }
+
+    /// Allocates a new value of type `U` in the arena and initializes it with
+    /// `val`.
     ///
     /// # Example
     ///
@@ -108,21 +124,17 @@ impl<T> DroplessArena<T> {
     ///
     /// assert_eq!(*x, 42);
     /// ```
-    pub fn alloc(&self, src: T) -> &mut T {
-        if self.start == self.end {
-            self.reserve(1);
-        }
+    pub fn alloc<U>(&self, val: U) -> &mut U {
+        assert!(std::mem::size_of::<U>() != 0);
 
         unsafe {
-            let dst = self.start.get();
-            self.start.set(self.start.get().add(1));
-            dst.write(src);
+            let dst = self.alloc_raw(std::alloc::Layout::new::<U>()) as *mut U;
+            dst.write(val);
             &mut *dst
         }
     }
 
-    /// Reserves additional space in the arena to meet the allocation requirements
-    /// of an object of type `T` with the specified additional size, in bytes.
+    /// Reserves at least `additional` free bytes in the arena.
     ///
     /// This method will allocate a new chunk of memory to store objects if the
     /// arena is full, and update the arena's start and end pointers to reflect
@@ -133,7 +145,7 @@ impl<T> DroplessArena<T> {
     /// ```
     /// use pochita::DroplessArena;
     ///
-    /// let arena = DroplessArena::<i32>::new();
+    /// let arena = DroplessArena::new();
     /// arena.reserve(10);
     /// ```
     #[cold]
@@ -141,10 +153,17 @@ impl<T> DroplessArena<T> {
     pub fn reserve(&self, additional: usize) {
         let mut chunks = self.chunks.borrow_mut();
 
-        let size = std::mem::size_of::<T>();
+        // The chunk we're about to retire may still have unused trailing space
+        // (the next allocation's size/alignment just didn't fit); record how
+        // much of it was actually written so `allocated_bytes` doesn't count
+        // that leftover space as allocated.
+        if let Some(chunk) = chunks.last_mut() {
+            chunk.record_written(self.start.get());
+        }
+
         let capacity = match chunks.last_mut() {
-            Some(chunk) => chunk.len().min(HUGE_PAGE / size / 2) * 2,
-            None => PAGE / size,
+            Some(chunk) => chunk.len().min(HUGE_PAGE / 2) * 2,
+            None => PAGE,
         };
 
         let mut chunk = unsafe { Chunk::new(additional.max(capacity)) };
@@ -152,10 +171,8 @@ impl<T> DroplessArena<T> {
         self.end.set(chunk.end());
         chunks.push(chunk);
     }
-}
 
-impl<T: Copy> DroplessArena<T> {
-    /// Allocates a new slice of type `T` in the arena and initializes it with a
+    /// Allocates a new slice of type `U` in the arena and initializes it with a
     /// copy of the contents of the `src` slice passed as an argument.
     ///
     /// # Example
@@ -169,7 +186,7 @@ impl<T: Copy> DroplessArena<T> {
     ///
     /// assert_eq!(src, dst);
     /// ```
-    pub fn alloc_slice_copy(&self, src: &[T]) -> &mut [T] {
+    pub fn alloc_slice_copy<U: Copy>(&self, src: &[U]) -> &mut [U] {
         let len = src.len();
 
         if len == 0 {
@@ -177,16 +194,14 @@ impl<T: Copy> DroplessArena<T> {
         }
 
         unsafe {
-            let dst = self.alloc_raw_slice(len);
+            let dst = self.alloc_raw(std::alloc::Layout::array::<U>(len).unwrap()) as *mut U;
             src.as_ptr().copy_to_nonoverlapping(dst, len);
             std::slice::from_raw_parts_mut(dst, len)
         }
     }
-}
 
-impl<T: Clone> DroplessArena<T> {
-    /// Allocates a slice of objects of type `T` in this arena and initializes
-    /// them with a clone of the values in the provided slice.
+    /// Allocates a slice of type `U` in this arena and initializes them with a
+    /// clone of the values in the provided slice.
     ///
     /// # Example
     ///
@@ -197,7 +212,7 @@ impl<T: Clone> DroplessArena<T> {
     /// let slice = arena.alloc_slice_clone(&[1, 2, 3]);
     /// assert_eq!(slice, &[1, 2, 3]);
     /// ```
-    pub fn alloc_slice_clone(&self, src: &[T]) -> &mut [T] {
+    pub fn alloc_slice_clone<U: Clone>(&self, src: &[U]) -> &mut [U] {
         let len = src.len();
 
         if len == 0 {
@@ -205,16 +220,14 @@ impl<T: Clone> DroplessArena<T> {
         }
 
         unsafe {
-            let dst = self.alloc_raw_slice(len);
+            let dst = self.alloc_raw(std::alloc::Layout::array::<U>(len).unwrap()) as *mut U;
             for (index, item) in src.iter().cloned().enumerate() {
                 dst.add(index).write(item);
             }
             std::slice::from_raw_parts_mut(dst, len)
         }
     }
-}
 
-impl DroplessArena<u8> {
     /// Allocates a new string in the arena and initializes it with a copy of the
     /// contents of the `src` string passed as an argument.
     ///
@@ -233,10 +246,542 @@ impl DroplessArena<u8> {
         let bytes = self.alloc_slice_copy(src.as_bytes());
         unsafe { std::str::from_utf8_unchecked_mut(bytes) }
     }
+
+    /// Returns the number of chunks the arena has allocated so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::DroplessArena;
+    ///
+    /// let arena = DroplessArena::new();
+    /// assert_eq!(arena.chunk_count(), 0);
+    /// arena.alloc(42);
+    /// assert_eq!(arena.chunk_count(), 1);
+    /// ```
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// Returns the total number of bytes reserved across all of the arena's
+    /// chunks, including space that hasn't been handed out by an allocation
+    /// yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::DroplessArena;
+    ///
+    /// let arena = DroplessArena::new();
+    /// arena.reserve(10);
+    /// assert!(arena.reserved_bytes() >= 10);
+    /// ```
+    pub fn reserved_bytes(&self) -> usize {
+        self.chunks.borrow().iter().map(Chunk::len).sum()
+    }
+
+    /// Returns the number of bytes actually written into the arena so far.
+    ///
+    /// This is always at most [`reserved_bytes`](Self::reserved_bytes); the
+    /// difference is how much headroom is left before the arena needs to grow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::DroplessArena;
+    ///
+    /// let arena = DroplessArena::new();
+    /// arena.alloc(42);
+    /// assert_eq!(arena.allocated_bytes(), std::mem::size_of::<i32>());
+    /// ```
+    pub fn allocated_bytes(&self) -> usize {
+        let chunks = self.chunks.borrow();
+
+        match chunks.split_last() {
+            Some((last, retired)) => {
+                let retired_bytes: usize = retired.iter().map(|chunk| chunk.written.get()).sum();
+                let last_start = (last.storage.as_ptr() as *mut u8).addr();
+                retired_bytes + (self.start.get().addr() - last_start)
+            }
+            None => 0,
+        }
+    }
+}
+
+/// A [`DroplessArena`] that can be shared and allocated into from multiple
+/// threads at once, for e.g. a parallel front-end building interned data.
+///
+/// The bump pointers and chunk list are the only state threads contend on, so
+/// a single lock around the whole [`DroplessArena`] is enough: references
+/// never move once written, so a `&T`/`&mut T` handed out to one thread stays
+/// valid for the arena's lifetime regardless of which thread reads it later.
+pub struct SyncDroplessArena {
+    arena: Mutex<DroplessArena>,
+}
+
+// SAFETY: every access to `arena`'s raw pointers and chunk list goes through
+// the `Mutex`, so its `Cell`/`RefCell` interior mutability is never observed
+// concurrently. The memory backing an allocation is boxed once and is never
+// moved or freed before `SyncDroplessArena` itself is dropped, so references
+// handed out from one thread remain valid when dereferenced on another.
+unsafe impl Send for SyncDroplessArena {}
+unsafe impl Sync for SyncDroplessArena {}
+
+impl Default for SyncDroplessArena {
+    fn default() -> SyncDroplessArena {
+        SyncDroplessArena::new()
+    }
+}
+
+impl SyncDroplessArena {
+    /// Creates a new, empty arena that can be allocated into from multiple
+    /// threads at once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// ```
+    pub fn new() -> SyncDroplessArena {
+        SyncDroplessArena { arena: Mutex::new(DroplessArena::new()) }
+    }
+
+    /// Allocates a new value of type `U` in the arena and initializes it with
+    /// `val`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// let x = arena.alloc(42);
+    ///
+    /// assert_eq!(*x, 42);
+    /// ```
+    pub fn alloc<U>(&self, val: U) -> &mut U {
+        let arena = self.arena.lock().unwrap();
+        let dst = arena.alloc(val) as *mut U;
+        unsafe { &mut *dst }
+    }
+
+    /// Allocates a new slice of type `U` in the arena and initializes it with a
+    /// copy of the contents of the `src` slice passed as an argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// let src = [1, 2, 3];
+    /// let dst = arena.alloc_slice_copy(&src);
+    ///
+    /// assert_eq!(src, dst);
+    /// ```
+    pub fn alloc_slice_copy<U: Copy>(&self, src: &[U]) -> &mut [U] {
+        let arena = self.arena.lock().unwrap();
+        let dst = arena.alloc_slice_copy(src) as *mut [U];
+        unsafe { &mut *dst }
+    }
+
+    /// Reserves at least `additional` free bytes in the arena.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// arena.reserve(10);
+    /// ```
+    pub fn reserve(&self, additional: usize) {
+        self.arena.lock().unwrap().reserve(additional);
+    }
+
+    /// Returns the number of chunks the arena has allocated so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// assert_eq!(arena.chunk_count(), 0);
+    /// arena.alloc(42);
+    /// assert_eq!(arena.chunk_count(), 1);
+    /// ```
+    pub fn chunk_count(&self) -> usize {
+        self.arena.lock().unwrap().chunk_count()
+    }
+
+    /// Returns the total number of bytes reserved across all of the arena's
+    /// chunks, including space that hasn't been handed out by an allocation
+    /// yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// arena.reserve(10);
+    /// assert!(arena.reserved_bytes() >= 10);
+    /// ```
+    pub fn reserved_bytes(&self) -> usize {
+        self.arena.lock().unwrap().reserved_bytes()
+    }
+
+    /// Returns the number of bytes actually written into the arena so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::SyncDroplessArena;
+    ///
+    /// let arena = SyncDroplessArena::new();
+    /// arena.alloc(42);
+    /// assert_eq!(arena.allocated_bytes(), std::mem::size_of::<i32>());
+    /// ```
+    pub fn allocated_bytes(&self) -> usize {
+        self.arena.lock().unwrap().allocated_bytes()
+    }
+}
+
+/// An arena that, unlike [`DroplessArena`], runs `T`'s destructor for every value
+/// it holds when the arena itself is dropped. Reach for this when `T` owns a
+/// resource (a `String`, `Vec`, `Box`, ...) that would otherwise leak.
+pub struct TypedArena<T> {
+    start: Cell<*mut T>,
+    end: Cell<*mut T>,
+    chunks: RefCell<Vec<TypedArenaChunk<T>>>,
+}
+
+impl<T> Default for TypedArena<T> {
+    fn default() -> TypedArena<T> {
+        TypedArena::new()
+    }
+}
+
+impl<T> TypedArena<T> {
+    unsafe fn alloc_raw_slice(&self, len: usize) -> *mut T {
+        self.ensure_capacity(len);
+
+        let dst = self.start.get();
+        self.start.set(dst.add(len));
+
+        dst
+    }
+}
+
+impl<T> TypedArena<T> {
+    /// Creates a new, empty arena that can be used to allocate objects of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena: TypedArena<String> = TypedArena::new();
+    /// ```
+    pub fn new() -> TypedArena<T> {
+        assert!(std::mem::size_of::<T>() != 0);
+
+        TypedArena {
+            start: Cell::new(std::ptr::null_mut()),
+            end: Cell::new(std::ptr::null_mut()),
+            chunks: Vec::new().into(),
+        }
+    }
+
+    /// Determines whether the arena has enough free space to allocate
+    /// `additional` more objects of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::<i32>::new();
+    /// assert_eq!(arena.can_allocate(10), false);
+    /// arena.ensure_capacity(10);
+    /// assert_eq!(arena.can_allocate(10), true);
+    /// ```
+    pub fn can_allocate(&self, additional: usize) -> bool {
+        let available_bytes = self.end.get().addr() - self.start.get().addr();
+        let additional_bytes = additional.checked_mul(std::mem::size_of::<T>()).unwrap();
+        available_bytes >= additional_bytes
+    }
+
+    /// Ensures that the arena has enough free space to allocate `additional`
+    /// more objects of type `T`.
+    ///
+    /// If the arena does not have enough free space, this method will reserve
+    /// additional space in the arena to meet the allocation requirements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::<i32>::new();
+    /// assert_eq!(arena.can_allocate(10), false);
+    /// arena.ensure_capacity(10);
+    /// assert_eq!(arena.can_allocate(10), true);
+    /// ```
+    pub fn ensure_capacity(&self, additional: usize) {
+        if !self.can_allocate(additional) {
+            self.reserve(additional);
+            debug_assert!(self.can_allocate(additional));
+        }
+    }
+
+    /// Allocates a new object of type `T` in the arena and initializes it with the
+    /// value of the `src` argument. Unlike [`DroplessArena::alloc`], `src` is
+    /// properly dropped once the arena itself is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::new();
+    /// let x = arena.alloc(String::from("Pochita"));
+    ///
+    /// assert_eq!(x, "Pochita");
+    /// ```
+    pub fn alloc(&self, src: T) -> &mut T {
+        if self.start == self.end {
+            self.reserve(1);
+        }
+
+        unsafe {
+            let dst = self.start.get();
+            self.start.set(dst.add(1));
+            dst.write(src);
+            &mut *dst
+        }
+    }
+
+    /// Allocates a slice in the arena and moves the contents of `iter` into it,
+    /// without first collecting into a `Vec`.
+    ///
+    /// `iter.next()` may itself allocate into this same arena (e.g. while
+    /// building up the AST nodes it yields), which would move `start`/`end` and
+    /// invalidate a pointer captured before iteration finished. So `iter` is
+    /// fully drained into a temporary `SmallVec` first (inline for up to 8
+    /// elements, on the heap beyond that), and only then is contiguous space
+    /// reserved and the elements moved in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::new();
+    /// let values = arena.alloc_from_iter(["Pochita", "Makima"].map(String::from));
+    ///
+    /// assert_eq!(values, ["Pochita", "Makima"]);
+    /// ```
+    pub fn alloc_from_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> &mut [T] {
+        let mut buffer: SmallVec<[T; 8]> = iter.into_iter().collect();
+        let len = buffer.len();
+
+        if len == 0 {
+            return &mut [];
+        }
+
+        unsafe {
+            let dst = self.alloc_raw_slice(len);
+            buffer.as_ptr().copy_to_nonoverlapping(dst, len);
+            // The elements now live in the arena; forget them here so they
+            // aren't dropped a second time when `buffer` goes out of scope.
+            buffer.set_len(0);
+            std::slice::from_raw_parts_mut(dst, len)
+        }
+    }
+
+    /// Reserves space in the arena for at least `additional` more objects of
+    /// type `T`.
+    ///
+    /// This method will allocate a new chunk of memory to store objects if the
+    /// arena is full, and update the arena's start and end pointers to reflect
+    /// the new chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::<i32>::new();
+    /// arena.reserve(10);
+    /// ```
+    #[cold]
+    #[inline(never)]
+    pub fn reserve(&self, additional: usize) {
+        let mut chunks = self.chunks.borrow_mut();
+
+        // The chunk we're about to retire only has `start - chunk.start()` of
+        // its entries initialized; record that now so `Drop` knows how much of
+        // it to walk.
+        if let Some(chunk) = chunks.last_mut() {
+            chunk.record_entries(self.start.get());
+        }
+
+        let size = std::mem::size_of::<T>();
+        let capacity = match chunks.last_mut() {
+            Some(chunk) => chunk.len().min(HUGE_PAGE / size / 2) * 2,
+            None => PAGE / size,
+        };
+
+        let mut chunk = unsafe { TypedArenaChunk::new(additional.max(capacity)) };
+        self.start.set(chunk.start());
+        self.end.set(chunk.end());
+        chunks.push(chunk);
+    }
+
+    /// Returns the number of chunks the arena has allocated so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::new();
+    /// assert_eq!(arena.chunk_count(), 0);
+    /// arena.alloc(String::from("Pochita"));
+    /// assert_eq!(arena.chunk_count(), 1);
+    /// ```
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// Returns the total number of bytes reserved across all of the arena's
+    /// chunks, including space that hasn't been handed out by an allocation
+    /// yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::<i32>::new();
+    /// arena.reserve(10);
+    /// assert!(arena.reserved_bytes() >= 10 * std::mem::size_of::<i32>());
+    /// ```
+    pub fn reserved_bytes(&self) -> usize {
+        let size = std::mem::size_of::<T>();
+        self.chunks.borrow().iter().map(|chunk| chunk.len() * size).sum()
+    }
+
+    /// Returns the number of bytes actually written into the arena so far.
+    ///
+    /// This is always at most [`reserved_bytes`](Self::reserved_bytes); the
+    /// difference is how much headroom is left before the arena needs to grow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pochita::TypedArena;
+    ///
+    /// let arena = TypedArena::new();
+    /// arena.alloc(String::from("Pochita"));
+    /// assert_eq!(arena.allocated_bytes(), std::mem::size_of::<String>());
+    /// ```
+    pub fn allocated_bytes(&self) -> usize {
+        let size = std::mem::size_of::<T>();
+        let chunks = self.chunks.borrow();
+
+        match chunks.split_last() {
+            Some((last, retired)) => {
+                // Each retired chunk already recorded exactly how many
+                // elements were written into it (`Drop` relies on the same
+                // count to know how much of it to walk).
+                let retired_bytes: usize = retired.iter().map(|chunk| chunk.entries.get() * size).sum();
+                let last_start = (last.storage.as_ptr() as *mut T).addr();
+                retired_bytes + (self.start.get().addr() - last_start)
+            }
+            None => 0,
+        }
+    }
+}
+
+unsafe impl<#[may_dangle] T> Drop for TypedArena<T> {
+    fn drop(&mut self) {
+        let mut chunks = self.chunks.borrow_mut();
+
+        // The current (last) chunk was never retired by `reserve`, so its live
+        // prefix runs up to `start` rather than whatever was last recorded.
+        if let Some(chunk) = chunks.last_mut() {
+            chunk.record_entries(self.start.get());
+        }
+
+        // Oldest to newest, though the order doesn't matter for soundness.
+        for chunk in chunks.iter_mut() {
+            unsafe { chunk.drop_entries() };
+        }
+    }
+}
+
+struct TypedArenaChunk<T> {
+    storage: NonNull<[MaybeUninit<T>]>,
+    /// Number of elements written into this chunk, recorded by
+    /// [`TypedArena::reserve`] right before the chunk is retired (or by
+    /// [`TypedArena`]'s own `Drop` for the chunk currently in use).
+    entries: Cell<usize>,
+}
+
+impl<T> TypedArenaChunk<T> {
+    unsafe fn new(capacity: usize) -> TypedArenaChunk<T> {
+        let uninit_slice = {
+            let mut uninit_slice = Vec::with_capacity(capacity);
+            uninit_slice.set_len(capacity);
+            uninit_slice.into_boxed_slice()
+        };
+        TypedArenaChunk {
+            storage: NonNull::new_unchecked(Box::into_raw(uninit_slice)),
+            entries: Cell::new(0),
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe { (*self.storage.as_ptr()).len() }
+    }
+
+    fn start(&mut self) -> *mut T {
+        self.storage.as_ptr() as *mut T
+    }
+
+    fn end(&mut self) -> *mut T {
+        unsafe { self.start().add((*self.storage.as_ptr()).len()) }
+    }
+
+    fn record_entries(&mut self, start: *mut T) {
+        let chunk_start = self.start().addr();
+        self.entries.set((start.addr() - chunk_start) / std::mem::size_of::<T>());
+    }
+
+    /// Drops the live prefix of this chunk, i.e. the entries written into it
+    /// before it was retired.
+    unsafe fn drop_entries(&mut self) {
+        let live = std::ptr::slice_from_raw_parts_mut(self.start(), self.entries.get());
+        std::ptr::drop_in_place(live);
+    }
+}
+
+unsafe impl<#[may_dangle] T> Drop for TypedArenaChunk<T> {
+    fn drop(&mut self) {
+        unsafe { drop(Box::from_raw(self.storage.as_mut())) };
+    }
 }
 
 struct Chunk<T> {
     storage: NonNull<[MaybeUninit<T>]>,
+    /// Number of bytes written into this chunk, recorded by
+    /// [`DroplessArena::reserve`] right before the chunk is retired.
+    written: Cell<usize>,
 }
 
 impl<T> Chunk<T> {
@@ -247,7 +792,7 @@ impl<T> Chunk<T> {
             uninit_slice.set_len(capacity);
             uninit_slice.into_boxed_slice()
         };
-        Chunk { storage: NonNull::new_unchecked(Box::into_raw(uninit_slice)) }
+        Chunk { storage: NonNull::new_unchecked(Box::into_raw(uninit_slice)), written: Cell::new(0) }
     }
 
     fn len(&self) -> usize {
@@ -261,6 +806,13 @@ impl<T> Chunk<T> {
     fn end(&mut self) -> *mut T {
         unsafe { self.start().add((*self.storage.as_ptr()).len()) }
     }
+
+    /// Records how much of this chunk (from its start, in bytes) was
+    /// actually written before it was retired in favor of a new chunk.
+    fn record_written(&mut self, start: *mut T) {
+        let chunk_start = self.start().addr();
+        self.written.set(start.addr() - chunk_start);
+    }
 }
 
 impl<T> Drop for Chunk<T> {
@@ -271,7 +823,7 @@ impl<T> Drop for Chunk<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::DroplessArena;
+    use crate::{DroplessArena, TypedArena, HUGE_PAGE, PAGE};
 
     #[test]
     fn alloc() {
@@ -306,4 +858,173 @@ mod tests {
         assert_eq!(arena.alloc_str("Makima"), "Makima");
         assert_eq!(arena.alloc_str("Pochita"), "Pochita");
     }
+
+    #[test]
+    fn alloc_mixed_alignment() {
+        let arena = DroplessArena::new();
+
+        // Interleaving odd-sized and highly-aligned allocations exercises the
+        // `alloc_raw` rounding: a misaligned `u64` would otherwise read/write
+        // out of bounds of its own allocation.
+        let a = arena.alloc(1u8);
+        let b: &mut u64 = arena.alloc(0xdead_beef_cafe_babeu64);
+        let c = arena.alloc_str("Pochita");
+
+        assert_eq!(*a, 1u8);
+        assert_eq!(*b, 0xdead_beef_cafe_babeu64);
+        assert_eq!((b as *const u64).align_offset(std::mem::align_of::<u64>()), 0);
+        assert_eq!(c, "Pochita");
+    }
+
+    #[test]
+    fn typed_arena_alloc() {
+        let arena = TypedArena::new();
+
+        assert_eq!(arena.alloc(String::from("Pochita")), "Pochita");
+    }
+
+    #[test]
+    fn typed_arena_drops_contents() {
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+
+        {
+            let arena = TypedArena::new();
+            // Allocate enough entries to span several chunks so `Drop` has to
+            // walk more than just the current one.
+            for _ in 0..5000 {
+                arena.alloc(Rc::clone(&counter));
+            }
+            assert_eq!(Rc::strong_count(&counter), 5001);
+        }
+
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn typed_arena_alloc_from_iter() {
+        let arena = TypedArena::new();
+
+        let values = arena.alloc_from_iter((0..512).map(|i| i.to_string()));
+        assert_eq!(values.len(), 512);
+        assert_eq!(values[0], "0");
+        assert_eq!(values[511], "511");
+    }
+
+    #[test]
+    fn typed_arena_alloc_from_iter_reentrant() {
+        // The iterator allocates into the same arena as it's drained, which
+        // must not corrupt the slice being built.
+        let arena: TypedArena<String> = TypedArena::new();
+
+        let values = arena.alloc_from_iter((0..64).map(|i| {
+            arena.alloc(format!("side-{i}"));
+            i.to_string()
+        }));
+
+        assert_eq!(values.len(), 64);
+        assert_eq!(values[0], "0");
+        assert_eq!(values[63], "63");
+    }
+
+    #[test]
+    fn typed_arena_alloc_from_iter_empty() {
+        let arena: TypedArena<String> = TypedArena::new();
+
+        assert!(arena.alloc_from_iter(std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn sync_dropless_arena_alloc_across_threads() {
+        use crate::SyncDroplessArena;
+
+        let arena = SyncDroplessArena::new();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|i| {
+                    let arena = &arena;
+                    scope.spawn(move || arena.alloc_slice_copy(&[i; 64]) as *const [i32] as *const i32 as usize)
+                })
+                .collect();
+
+            let addresses: Vec<usize> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+            // Every thread's allocation must land in distinct, non-overlapping
+            // memory, and the values must still be intact afterwards.
+            for (i, &address) in addresses.iter().enumerate() {
+                let slice = unsafe { std::slice::from_raw_parts(address as *const i32, 64) };
+                assert!(slice.iter().all(|&v| v == i as i32));
+            }
+        });
+    }
+
+    #[test]
+    fn introspection_across_multiple_chunks() {
+        let arena = DroplessArena::new();
+        assert_eq!(arena.chunk_count(), 0);
+        assert_eq!(arena.reserved_bytes(), 0);
+        assert_eq!(arena.allocated_bytes(), 0);
+
+        // Allocate enough to force the arena to grow past its first chunk.
+        for i in 0..100_000u64 {
+            arena.alloc(i);
+        }
+
+        assert!(arena.chunk_count() >= 2);
+        assert_eq!(arena.allocated_bytes(), 100_000 * std::mem::size_of::<u64>());
+        assert!(arena.reserved_bytes() >= arena.allocated_bytes());
+    }
+
+    #[test]
+    fn dropless_arena_allocated_bytes_excludes_retired_leftover() {
+        let arena = DroplessArena::new();
+
+        // Fill the first (4096-byte) chunk to within 3 bytes of capacity with
+        // single-byte allocations.
+        for _ in 0..(PAGE - 3) {
+            arena.alloc(0u8);
+        }
+
+        // A `u64` doesn't fit in the remaining 3 bytes, so this retires the
+        // first chunk with 3 bytes of unused space and allocates in a new one.
+        arena.alloc(0u64);
+
+        assert_eq!(arena.allocated_bytes(), (PAGE - 3) + std::mem::size_of::<u64>());
+    }
+
+    #[test]
+    fn typed_arena_allocated_bytes_excludes_retired_leftover() {
+        let arena: TypedArena<u64> = TypedArena::new();
+
+        let capacity = PAGE / std::mem::size_of::<u64>();
+
+        // Fill the first chunk to within 3 slots of capacity.
+        for i in 0..(capacity - 3) {
+            arena.alloc(i as u64);
+        }
+
+        // These 10 elements don't fit in the remaining 3 slots, so the first
+        // chunk is retired with 3 slots of unused space.
+        let extra = arena.alloc_from_iter((0..10).map(|i| i as u64));
+        assert_eq!(extra.len(), 10);
+
+        assert_eq!(arena.allocated_bytes(), (capacity - 3 + 10) * std::mem::size_of::<u64>());
+    }
+
+    #[test]
+    fn alloc_raw_reserves_slack_for_large_alignment() {
+        let arena = DroplessArena::new();
+
+        // A chunk's backing storage is only byte-aligned, so a request whose
+        // size leaves the chunk with no slack for a large alignment must
+        // still terminate: `reserve` needs to pad for `layout.align()`, or
+        // `alloc_raw` loops forever reserving same-sized, still-misaligned
+        // chunks.
+        let layout = std::alloc::Layout::from_size_align(HUGE_PAGE - 64, HUGE_PAGE).unwrap();
+        let ptr = arena.alloc_raw(layout);
+
+        assert_eq!(ptr.addr() % HUGE_PAGE, 0);
+    }
 }